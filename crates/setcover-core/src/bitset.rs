@@ -39,18 +39,87 @@ fn coverage_gain(set_bits: &BitSet, uncovered: &BitSet) -> usize {
 }
 
 /// Greedy set cover using bitsets.
-/// `sets_bits[i]` is the bitset representation of set i.
-pub fn greedy_set_cover_bitset(universe_size: usize, sets_bits: &[BitSet]) -> Option<Vec<usize>> {
+///
+/// `sets_bits[i]` is the bitset representation of set i and `costs[i]` its
+/// cost; each round the unused set minimizing `cost / gain` over sets with
+/// positive gain is chosen, which reduces to the unweighted greedy when
+/// every cost is `1.0`. Returns the indices of the chosen sets plus their
+/// accumulated cost, or None if coverage is impossible.
+pub fn greedy_set_cover_bitset(
+    universe_size: usize,
+    sets_bits: &[BitSet],
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
     if universe_size == 0 {
-        return Some(Vec::new());
+        return Some((Vec::new(), 0.0));
     }
 
     let mut uncovered = make_uncovered(universe_size);
     let mut remaining = universe_size;
     let mut chosen = Vec::new();
     let mut used = vec![false; sets_bits.len()];
+    let mut total_cost = 0.0;
 
     while remaining > 0 {
+        let mut best_idx: Option<usize> = None;
+        let mut best_ratio = f64::INFINITY;
+
+        for (i, bits) in sets_bits.iter().enumerate() {
+            if used[i] {
+                continue;
+            }
+            let gain = coverage_gain(bits, &uncovered);
+            if gain == 0 {
+                continue;
+            }
+            let ratio = costs[i] / gain as f64;
+            if ratio < best_ratio {
+                best_ratio = ratio;
+                best_idx = Some(i);
+            }
+        }
+
+        let idx = best_idx?;
+
+        used[idx] = true;
+        chosen.push(idx);
+        total_cost += costs[idx];
+
+        let bits = &sets_bits[idx];
+        for (u, s) in uncovered.iter_mut().zip(bits.iter()) {
+            let newly_covered = *u & *s;
+            let count = newly_covered.count_ones() as usize;
+            if count > 0 {
+                remaining -= count;
+            }
+            *u &= !s;
+        }
+    }
+
+    Some((chosen, total_cost))
+}
+
+/// Greedy maximum coverage using bitsets.
+///
+/// Selects at most `k` sets, each round picking the unused set with the
+/// largest marginal gain against `uncovered`, and stops early once no
+/// remaining set has positive gain. Returns the indices of the chosen sets
+/// (in selection order) together with the number of elements they cover.
+pub fn greedy_max_cover_bitset(
+    universe_size: usize,
+    sets_bits: &[BitSet],
+    k: usize,
+) -> (Vec<usize>, usize) {
+    if universe_size == 0 || k == 0 {
+        return (Vec::new(), 0);
+    }
+
+    let mut uncovered = make_uncovered(universe_size);
+    let mut covered = 0usize;
+    let mut chosen = Vec::new();
+    let mut used = vec![false; sets_bits.len()];
+
+    while chosen.len() < k {
         let mut best_idx: Option<usize> = None;
         let mut best_gain = 0usize;
 
@@ -67,7 +136,7 @@ pub fn greedy_set_cover_bitset(universe_size: usize, sets_bits: &[BitSet]) -> Op
 
         let idx = match best_idx {
             Some(i) if best_gain > 0 => i,
-            _ => return None,
+            _ => break,
         };
 
         used[idx] = true;
@@ -78,11 +147,128 @@ pub fn greedy_set_cover_bitset(universe_size: usize, sets_bits: &[BitSet]) -> Op
             let newly_covered = *u & *s;
             let count = newly_covered.count_ones() as usize;
             if count > 0 {
-                remaining -= count;
+                covered += count;
             }
             *u &= !s;
         }
     }
 
-    Some(chosen)
+    (chosen, covered)
+}
+
+/// Per-set bitset representation, chosen by density so that large, sparse
+/// universes don't pay for a full `universe_size / 64`-word allocation per
+/// set. `uncovered` itself always stays a plain dense [`BitSet`].
+pub enum HybridBitSet {
+    /// Sorted, deduplicated element ids; used when the set is sparse
+    /// relative to the universe.
+    Sparse(Vec<usize>),
+    /// Dense word vector; used when the set is dense relative to the
+    /// universe.
+    Dense(BitSet),
+}
+
+/// Build the per-set representation `make_bitset` would use, but pick
+/// sparse storage when it is cheaper than a dense word vector, i.e. when
+/// the set has fewer elements than `universe_size / 64` words.
+pub fn make_hybrid_bitset(universe_size: usize, elements: &[usize]) -> HybridBitSet {
+    let num_words = universe_size.div_ceil(64);
+
+    if elements.len() < num_words {
+        let mut sparse: Vec<usize> = elements.iter().copied().filter(|&e| e < universe_size).collect();
+        sparse.sort_unstable();
+        sparse.dedup();
+        HybridBitSet::Sparse(sparse)
+    } else {
+        HybridBitSet::Dense(make_bitset(universe_size, elements))
+    }
+}
+
+fn hybrid_coverage_gain(set_bits: &HybridBitSet, uncovered: &BitSet) -> usize {
+    match set_bits {
+        HybridBitSet::Sparse(elements) => elements
+            .iter()
+            .filter(|&&e| (uncovered[e / 64] >> (e % 64)) & 1 == 1)
+            .count(),
+        HybridBitSet::Dense(bits) => coverage_gain(bits, uncovered),
+    }
+}
+
+fn hybrid_apply_coverage(set_bits: &HybridBitSet, uncovered: &mut BitSet) -> usize {
+    match set_bits {
+        HybridBitSet::Sparse(elements) => {
+            let mut newly_covered = 0usize;
+            for &e in elements {
+                let word = e / 64;
+                let bit = e % 64;
+                if (uncovered[word] >> bit) & 1 == 1 {
+                    uncovered[word] &= !(1u64 << bit);
+                    newly_covered += 1;
+                }
+            }
+            newly_covered
+        }
+        HybridBitSet::Dense(bits) => {
+            let mut newly_covered = 0usize;
+            for (u, s) in uncovered.iter_mut().zip(bits.iter()) {
+                let newly = *u & *s;
+                newly_covered += newly.count_ones() as usize;
+                *u &= !s;
+            }
+            newly_covered
+        }
+    }
+}
+
+/// Greedy set cover over [`HybridBitSet`] sets, for large, sparse universes
+/// where an all-dense [`greedy_set_cover_bitset`] would waste time and
+/// memory scanning mostly-empty words. `hybrid_coverage_gain` and the
+/// coverage update for a sparse set only touch its listed elements, costing
+/// `O(|set|)` instead of `O(universe_size / 64)`. Otherwise identical to
+/// [`greedy_set_cover_bitset`]: each round the unused set minimizing
+/// `cost / gain` is chosen, reducing to the unweighted greedy when every
+/// cost is `1.0`.
+pub fn greedy_set_cover_bitset_hybrid(
+    universe_size: usize,
+    sets_bits: &[HybridBitSet],
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
+    if universe_size == 0 {
+        return Some((Vec::new(), 0.0));
+    }
+
+    let mut uncovered = make_uncovered(universe_size);
+    let mut remaining = universe_size;
+    let mut chosen = Vec::new();
+    let mut used = vec![false; sets_bits.len()];
+    let mut total_cost = 0.0;
+
+    while remaining > 0 {
+        let mut best_idx: Option<usize> = None;
+        let mut best_ratio = f64::INFINITY;
+
+        for (i, bits) in sets_bits.iter().enumerate() {
+            if used[i] {
+                continue;
+            }
+            let gain = hybrid_coverage_gain(bits, &uncovered);
+            if gain == 0 {
+                continue;
+            }
+            let ratio = costs[i] / gain as f64;
+            if ratio < best_ratio {
+                best_ratio = ratio;
+                best_idx = Some(i);
+            }
+        }
+
+        let idx = best_idx?;
+
+        used[idx] = true;
+        chosen.push(idx);
+        total_cost += costs[idx];
+        remaining -= hybrid_apply_coverage(&sets_bits[idx], &mut uncovered);
+    }
+
+    Some((chosen, total_cost))
 }
@@ -1,18 +1,91 @@
 /// Greedy set cover on a dense universe {0, 1, ..., universe_size - 1}.
 ///
 /// `sets[i]` is list of elements in set i (each in 0..universe_size).
-/// Returns indices of chosen sets, or None if coverage impossible.
-pub fn greedy_set_cover_dense(universe_size: usize, sets: &[Vec<usize>]) -> Option<Vec<usize>> {
+/// `costs[i]` is the cost of picking set i; each round the unused set
+/// minimizing `cost / gain` over sets with positive gain is chosen, which
+/// reduces to the unweighted greedy when every cost is `1.0`.
+/// Returns the indices of the chosen sets plus their accumulated cost, or
+/// None if coverage is impossible.
+pub fn greedy_set_cover_dense(
+    universe_size: usize,
+    sets: &[Vec<usize>],
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
     if universe_size == 0 {
-        return Some(Vec::new());
+        return Some((Vec::new(), 0.0));
     }
 
     let mut uncovered = vec![true; universe_size];
     let mut remaining = universe_size;
     let mut chosen_sets = Vec::new();
     let mut used = vec![false; sets.len()];
+    let mut total_cost = 0.0;
 
     while remaining > 0 {
+        let mut best_idx: Option<usize> = None;
+        let mut best_ratio = f64::INFINITY;
+
+        for (i, s) in sets.iter().enumerate() {
+            if used[i] {
+                continue;
+            }
+            let mut cover = 0usize;
+            for &e in s {
+                if e < universe_size && uncovered[e] {
+                    cover += 1;
+                }
+            }
+            if cover == 0 {
+                continue;
+            }
+            let ratio = costs[i] / cover as f64;
+            if ratio < best_ratio {
+                best_ratio = ratio;
+                best_idx = Some(i);
+            }
+        }
+
+        let idx = best_idx?;
+
+        used[idx] = true;
+        chosen_sets.push(idx);
+        total_cost += costs[idx];
+
+        for &e in &sets[idx] {
+            if e < universe_size && uncovered[e] {
+                uncovered[e] = false;
+                remaining -= 1;
+                if remaining == 0 {
+                    break;
+                }
+            }
+        }
+    }
+
+    Some((chosen_sets, total_cost))
+}
+
+/// Greedy maximum coverage on a dense universe {0, 1, ..., universe_size - 1}.
+///
+/// Selects at most `k` sets, each round picking the unused set with the
+/// largest marginal gain against `uncovered`, and stops early once no
+/// remaining set has positive gain. Returns the indices of the chosen sets
+/// (in selection order) together with the number of elements they cover.
+pub fn greedy_max_cover_dense(
+    universe_size: usize,
+    sets: &[Vec<usize>],
+    k: usize,
+) -> (Vec<usize>, usize) {
+    if universe_size == 0 || k == 0 {
+        return (Vec::new(), 0);
+    }
+
+    let mut uncovered = vec![true; universe_size];
+    let mut covered = 0usize;
+    let mut chosen_sets = Vec::new();
+    let mut used = vec![false; sets.len()];
+
+    while chosen_sets.len() < k {
         let mut best_idx: Option<usize> = None;
         let mut best_cover = 0usize;
 
@@ -34,7 +107,7 @@ pub fn greedy_set_cover_dense(universe_size: usize, sets: &[Vec<usize>]) -> Opti
 
         let idx = match best_idx {
             Some(i) if best_cover > 0 => i,
-            _ => return None,
+            _ => break,
         };
 
         used[idx] = true;
@@ -43,13 +116,10 @@ pub fn greedy_set_cover_dense(universe_size: usize, sets: &[Vec<usize>]) -> Opti
         for &e in &sets[idx] {
             if e < universe_size && uncovered[e] {
                 uncovered[e] = false;
-                remaining -= 1;
-                if remaining == 0 {
-                    break;
-                }
+                covered += 1;
             }
         }
     }
 
-    Some(chosen_sets)
+    (chosen_sets, covered)
 }
@@ -0,0 +1,130 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Heap entry tracking a lower bound on a set's current cost-effectiveness
+/// ratio (`cost / gain`).
+///
+/// `ratio` is only the true value when `stamp` matches the solver's
+/// current `round`; otherwise it is a stale lower bound from an earlier
+/// round (gain only shrinks as coverage grows, so `cost / gain` only
+/// grows — a ratio computed against a larger, earlier `uncovered` can only
+/// be smaller than or equal to the true current ratio).
+///
+/// `Ord` is reversed so this min-heap-by-ratio can be built on top of
+/// [`BinaryHeap`], which pops the maximum by `Ord` first. Equal ratios are
+/// broken by the lower `set_idx`, matching the plain greedy's tie-break.
+struct HeapEntry {
+    ratio: f64,
+    set_idx: usize,
+    stamp: usize,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for HeapEntry {}
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .ratio
+            .partial_cmp(&self.ratio)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.set_idx.cmp(&self.set_idx))
+    }
+}
+
+/// Lazy ("accelerated") greedy set cover on a dense universe {0, 1, ...,
+/// universe_size - 1}.
+///
+/// Exploits submodularity: marginal gains only shrink as coverage grows, so
+/// `cost / gain` only grows, meaning a ratio computed in an earlier round is
+/// always a lower bound on a set's true current ratio. Each round pops the
+/// heap entry with the smallest stored ratio; if it was already recomputed
+/// this round (`stamp == round`), every other entry's stored ratio is a
+/// lower bound on its true ratio and therefore no smaller than this one, so
+/// it is provably the most cost-effective set and gets selected. Otherwise
+/// its ratio is recomputed against the current `uncovered` state and it is
+/// pushed back with `stamp` set to the current round. This typically
+/// recomputes only a handful of ratios per round instead of rescanning
+/// every set, while producing the same selection as the cost-weighted
+/// plain greedy (`greedy_set_cover_dense`/`greedy_set_cover_bitset`), which
+/// reduces to the unweighted greedy when every cost is `1.0`.
+///
+/// Returns the indices of the chosen sets plus their accumulated cost, or
+/// None if coverage is impossible.
+pub fn greedy_set_cover_lazy(
+    universe_size: usize,
+    sets: &[Vec<usize>],
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
+    if universe_size == 0 {
+        return Some((Vec::new(), 0.0));
+    }
+
+    let mut uncovered = vec![true; universe_size];
+    let mut remaining = universe_size;
+    let mut used = vec![false; sets.len()];
+    let mut chosen = Vec::new();
+    let mut total_cost = 0.0;
+    let mut round = 0usize;
+
+    let mut heap: BinaryHeap<HeapEntry> = sets
+        .iter()
+        .enumerate()
+        .filter_map(|(set_idx, s)| {
+            let gain = s.iter().filter(|&&e| e < universe_size).count();
+            if gain == 0 {
+                return None;
+            }
+            Some(HeapEntry {
+                ratio: costs[set_idx] / gain as f64,
+                set_idx,
+                stamp: 0,
+            })
+        })
+        .collect();
+
+    while remaining > 0 {
+        let entry = heap.pop()?;
+        if used[entry.set_idx] {
+            continue;
+        }
+
+        if entry.stamp == round {
+            let idx = entry.set_idx;
+            used[idx] = true;
+            chosen.push(idx);
+            total_cost += costs[idx];
+            round += 1;
+
+            for &e in &sets[idx] {
+                if e < universe_size && uncovered[e] {
+                    uncovered[e] = false;
+                    remaining -= 1;
+                }
+            }
+        } else {
+            let gain = sets[entry.set_idx]
+                .iter()
+                .filter(|&&e| e < universe_size && uncovered[e])
+                .count();
+            if gain == 0 {
+                continue;
+            }
+            heap.push(HeapEntry {
+                ratio: costs[entry.set_idx] / gain as f64,
+                set_idx: entry.set_idx,
+                stamp: round,
+            });
+        }
+    }
+
+    Some((chosen, total_cost))
+}
@@ -1,73 +1,160 @@
 mod bitset;
 mod dense;
+mod lazy;
 mod mapping;
-
-pub use bitset::{greedy_set_cover_bitset, BitSet};
-pub use dense::greedy_set_cover_dense;
+mod max_cover;
+
+pub use bitset::{
+    greedy_max_cover_bitset, greedy_set_cover_bitset, greedy_set_cover_bitset_hybrid, BitSet,
+    HybridBitSet,
+};
+pub use dense::{greedy_max_cover_dense, greedy_set_cover_dense};
+pub use lazy::greedy_set_cover_lazy;
 pub use mapping::compress_universe;
+pub use max_cover::{maximum_cover, CoverSet, MaxCover};
 
 use std::collections::HashMap;
 use std::hash::Hash;
 
 /// Public router that mirrors the historical API.
 ///
-/// Accepts a `HashMap` of sets keyed by user identifiers and returns the
-/// keys of the chosen sets (sorted for stability).
-pub fn greedy_set_cover<K, T>(sets: &HashMap<K, Vec<T>>, algo: String) -> Vec<K>
+/// Accepts a `HashMap` of sets keyed by user identifiers, plus an optional
+/// per-key cost map (missing keys, or `costs: None`, default to `1.0`), and
+/// returns the keys of the chosen sets (sorted for stability) together with
+/// their accumulated cost. With uniform costs this minimizes the number of
+/// sets; with custom costs it minimizes total cost via the cost-effectiveness
+/// greedy rule (`cost / gain`).
+pub fn greedy_set_cover<K, T>(
+    sets: &HashMap<K, Vec<T>>,
+    algo: String,
+    costs: Option<&HashMap<K, f64>>,
+) -> (Vec<K>, f64)
 where
     K: Clone + Hash + Eq + std::fmt::Debug + Ord,
     T: Clone + Hash + Eq + std::fmt::Debug,
 {
     let (keys, vec_sets) = materialize_sets(sets);
-    let cover = run_greedy(&vec_sets, &algo).unwrap_or_else(|| {
+    let cost_vec = costs_for(&keys, costs);
+    let (cover, total_cost) = run_greedy(&vec_sets, &algo, &cost_vec).unwrap_or_else(|| {
         panic!("Error: Unable to find a set cover using algorithm {algo}");
     });
 
     let mut chosen: Vec<K> = cover.into_iter().map(|idx| keys[idx].clone()).collect();
     chosen.sort();
-    chosen
+    (chosen, total_cost)
 }
 
 /// Variant where the set elements are already dense integers.
-pub fn greedy_set_cover_int_elements<K>(sets: &HashMap<K, Vec<usize>>, algo: String) -> Vec<K>
+pub fn greedy_set_cover_int_elements<K>(
+    sets: &HashMap<K, Vec<usize>>,
+    algo: String,
+    costs: Option<&HashMap<K, f64>>,
+) -> (Vec<K>, f64)
 where
     K: Clone + Hash + Eq + std::fmt::Debug + Ord,
 {
     let (keys, vec_sets) = materialize_sets(sets);
-    let cover = run_greedy(&vec_sets, &algo).unwrap_or_else(|| {
+    let cost_vec = costs_for(&keys, costs);
+    let (cover, total_cost) = run_greedy(&vec_sets, &algo, &cost_vec).unwrap_or_else(|| {
         panic!("Error: Unable to find a set cover using algorithm {algo}");
     });
 
     let mut chosen: Vec<K> = cover.into_iter().map(|idx| keys[idx].clone()).collect();
     chosen.sort();
-    chosen
+    (chosen, total_cost)
+}
+
+/// Build a per-set cost vector aligned with `keys`, defaulting missing
+/// entries (or the absence of a cost map entirely) to `1.0`.
+fn costs_for<K: Hash + Eq>(keys: &[K], costs: Option<&HashMap<K, f64>>) -> Vec<f64> {
+    match costs {
+        Some(costs) => keys.iter().map(|k| *costs.get(k).unwrap_or(&1.0)).collect(),
+        None => vec![1.0; keys.len()],
+    }
+}
+
+/// Public router for the budget-limited maximum-coverage problem.
+///
+/// Picks at most `k` sets maximizing the number of distinct universe elements
+/// covered, and returns the keys of the chosen sets (sorted for stability)
+/// together with the number of elements covered.
+pub fn greedy_max_cover<K, T>(
+    sets: &HashMap<K, Vec<T>>,
+    algo: String,
+    k: usize,
+) -> (Vec<K>, usize)
+where
+    K: Clone + Hash + Eq + std::fmt::Debug + Ord,
+    T: Clone + Hash + Eq + std::fmt::Debug,
+{
+    let (keys, vec_sets) = materialize_sets(sets);
+    let (cover, covered) = run_max_cover(&vec_sets, &algo, k);
+
+    let mut chosen: Vec<K> = cover.into_iter().map(|idx| keys[idx].clone()).collect();
+    chosen.sort();
+    (chosen, covered)
 }
 
 /// Route across the available greedy strategies for a generic Vec-of-Vecs input.
+///
+/// `costs[i]` is the cost of set i; pass a vector of `1.0`s for the
+/// unweighted problem. Returns the chosen indices plus their accumulated
+/// cost, or None if not coverable.
 pub fn greedy_set_cover_generic<T: Eq + Hash + Clone>(
     sets: &[Vec<T>],
     algo: &str,
-) -> Option<Vec<usize>> {
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
     match algo {
-        "dense" => greedy_set_cover_dense_generic(sets),
-        "bitset" => greedy_set_cover_bitset_generic(sets),
-        "textbook" => greedy_set_cover_textbook_generic(sets),
+        "dense" => greedy_set_cover_dense_generic(sets, costs),
+        "bitset" => greedy_set_cover_bitset_generic(sets, costs),
+        "textbook" => greedy_set_cover_textbook_generic(sets, costs),
+        "lazy" => greedy_set_cover_lazy_generic(sets, costs),
         _ => None,
     }
 }
 
-fn run_greedy<T: Eq + Hash + Clone>(sets: &[Vec<T>], algo: &str) -> Option<Vec<usize>> {
+/// Route across the available greedy strategies for the max-coverage problem.
+pub fn greedy_max_cover_generic<T: Eq + Hash + Clone>(
+    sets: &[Vec<T>],
+    algo: &str,
+    k: usize,
+) -> (Vec<usize>, usize) {
+    match algo {
+        "dense" => greedy_max_cover_dense_generic(sets, k),
+        "bitset" => greedy_max_cover_bitset_generic(sets, k),
+        _ => (Vec::new(), 0),
+    }
+}
+
+fn run_max_cover<T: Eq + Hash + Clone>(sets: &[Vec<T>], algo: &str, k: usize) -> (Vec<usize>, usize) {
+    let route = match algo {
+        "greedy-standard" => "dense",
+        "greedy-bitvec" => "bitset",
+        other => {
+            panic!("Wrong algo choice '{other}', must be 'greedy-bitvec' or 'greedy-standard'");
+        }
+    };
+    greedy_max_cover_generic(sets, route, k)
+}
+
+fn run_greedy<T: Eq + Hash + Clone>(
+    sets: &[Vec<T>],
+    algo: &str,
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
     let route = match algo {
         "greedy-standard" => "dense",
         "greedy-bitvec" => "bitset",
         "greedy-textbook" => "textbook",
+        "greedy-lazy" => "lazy",
         other => {
             panic!(
-                "Wrong algo choice '{other}', must be 'greedy-bitvec', 'greedy-standard' or 'greedy-textbook'"
+                "Wrong algo choice '{other}', must be 'greedy-bitvec', 'greedy-standard', 'greedy-textbook' or 'greedy-lazy'"
             );
         }
     };
-    greedy_set_cover_generic(sets, route)
+    greedy_set_cover_generic(sets, route, costs)
 }
 
 fn materialize_sets<K, T>(sets: &HashMap<K, Vec<T>>) -> (Vec<K>, Vec<Vec<T>>)
@@ -93,48 +180,122 @@ where
 
 /// Generic wrapper: greedy dense algorithm for arbitrary `T`.
 ///
-/// Returns indices of chosen sets (into `sets`), or None if not coverable.
-pub fn greedy_set_cover_dense_generic<T: Eq + Hash + Clone>(sets: &[Vec<T>]) -> Option<Vec<usize>> {
+/// Returns indices of chosen sets (into `sets`) plus their accumulated
+/// cost, or None if not coverable.
+pub fn greedy_set_cover_dense_generic<T: Eq + Hash + Clone>(
+    sets: &[Vec<T>],
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
     let (dense_sets, universe) = mapping::compress_universe(sets);
     let universe_size = universe.len();
 
-    dense::greedy_set_cover_dense(universe_size, &dense_sets)
+    dense::greedy_set_cover_dense(universe_size, &dense_sets, costs)
 }
 
+/// Universe size above which `greedy_set_cover_bitset_generic` routes to the
+/// hybrid sparse/dense solver instead of an all-dense bitset, since a dense
+/// `universe_size / 64`-word-per-set allocation starts to dominate once the
+/// universe is this large.
+const HYBRID_BITSET_UNIVERSE_THRESHOLD: usize = 4096;
+
 /// Generic wrapper: greedy bitset algorithm for arbitrary `T`.
 ///
-/// Returns indices of chosen sets (into `sets`), or None if not coverable.
+/// Routes to the hybrid sparse/dense solver for large universes (see
+/// [`HYBRID_BITSET_UNIVERSE_THRESHOLD`]), and to the plain all-dense
+/// bitset solver otherwise. Returns indices of chosen sets (into `sets`)
+/// plus their accumulated cost, or None if not coverable.
 pub fn greedy_set_cover_bitset_generic<T: Eq + Hash + Clone>(
     sets: &[Vec<T>],
-) -> Option<Vec<usize>> {
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
     let (dense_sets, universe) = mapping::compress_universe(sets);
     let universe_size = universe.len();
 
+    if universe_size > HYBRID_BITSET_UNIVERSE_THRESHOLD {
+        let sets_bits: Vec<HybridBitSet> = dense_sets
+            .iter()
+            .map(|s| bitset::make_hybrid_bitset(universe_size, s))
+            .collect();
+        return bitset::greedy_set_cover_bitset_hybrid(universe_size, &sets_bits, costs);
+    }
+
     let sets_bits: Vec<BitSet> = dense_sets
         .iter()
         .map(|s| bitset::make_bitset(universe_size, s))
         .collect();
 
-    bitset::greedy_set_cover_bitset(universe_size, &sets_bits)
+    bitset::greedy_set_cover_bitset(universe_size, &sets_bits, costs)
+}
+
+/// Generic wrapper: lazy (accelerated) greedy algorithm for arbitrary `T`.
+///
+/// Returns indices of chosen sets (into `sets`) plus their accumulated
+/// cost, or None if not coverable.
+pub fn greedy_set_cover_lazy_generic<T: Eq + Hash + Clone>(
+    sets: &[Vec<T>],
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
+    let (dense_sets, universe) = mapping::compress_universe(sets);
+    let universe_size = universe.len();
+
+    lazy::greedy_set_cover_lazy(universe_size, &dense_sets, costs)
 }
 
-/// Textbook greedy: pick the set covering the most uncovered elements each round.
+/// Generic wrapper: greedy dense max-coverage algorithm for arbitrary `T`.
+///
+/// Returns indices of the chosen sets (into `sets`, at most `k` of them) and
+/// the number of elements they cover.
+pub fn greedy_max_cover_dense_generic<T: Eq + Hash + Clone>(
+    sets: &[Vec<T>],
+    k: usize,
+) -> (Vec<usize>, usize) {
+    let (dense_sets, universe) = mapping::compress_universe(sets);
+    let universe_size = universe.len();
+
+    dense::greedy_max_cover_dense(universe_size, &dense_sets, k)
+}
+
+/// Generic wrapper: greedy bitset max-coverage algorithm for arbitrary `T`.
+///
+/// Returns indices of the chosen sets (into `sets`, at most `k` of them) and
+/// the number of elements they cover.
+pub fn greedy_max_cover_bitset_generic<T: Eq + Hash + Clone>(
+    sets: &[Vec<T>],
+    k: usize,
+) -> (Vec<usize>, usize) {
+    let (dense_sets, universe) = mapping::compress_universe(sets);
+    let universe_size = universe.len();
+
+    let sets_bits: Vec<BitSet> = dense_sets
+        .iter()
+        .map(|s| bitset::make_bitset(universe_size, s))
+        .collect();
+
+    bitset::greedy_max_cover_bitset(universe_size, &sets_bits, k)
+}
+
+/// Textbook greedy: pick the unused set minimizing `cost / gain` each round
+/// (equivalent to picking the most uncovered elements when every cost is
+/// `1.0`). Returns the chosen indices plus their accumulated cost, or None
+/// if coverage is impossible.
 pub fn greedy_set_cover_textbook_generic<T: Eq + Hash + Clone>(
     sets: &[Vec<T>],
-) -> Option<Vec<usize>> {
+    costs: &[f64],
+) -> Option<(Vec<usize>, f64)> {
     use std::collections::HashSet;
 
     let mut uncovered: HashSet<T> = sets.iter().flatten().cloned().collect();
     if uncovered.is_empty() {
-        return Some(Vec::new());
+        return Some((Vec::new(), 0.0));
     }
 
     let mut chosen = Vec::new();
     let mut used = vec![false; sets.len()];
+    let mut total_cost = 0.0;
 
     while !uncovered.is_empty() {
         let mut best_idx: Option<usize> = None;
-        let mut best_gain = 0usize;
+        let mut best_ratio = f64::INFINITY;
 
         for (idx, set) in sets.iter().enumerate() {
             if used[idx] {
@@ -142,26 +303,28 @@ pub fn greedy_set_cover_textbook_generic<T: Eq + Hash + Clone>(
             }
 
             let gain = set.iter().filter(|e| uncovered.contains(*e)).count();
-            if gain > best_gain {
-                best_gain = gain;
+            if gain == 0 {
+                continue;
+            }
+            let ratio = costs[idx] / gain as f64;
+            if ratio < best_ratio {
+                best_ratio = ratio;
                 best_idx = Some(idx);
             }
         }
 
-        let idx = match best_idx {
-            Some(i) if best_gain > 0 => i,
-            _ => return None,
-        };
+        let idx = best_idx?;
 
         used[idx] = true;
         chosen.push(idx);
+        total_cost += costs[idx];
 
         for element in &sets[idx] {
             uncovered.remove(element);
         }
     }
 
-    Some(chosen)
+    Some((chosen, total_cost))
 }
 
 #[cfg(test)]
@@ -183,9 +346,9 @@ mod tests {
         sets.insert("B".to_string(), vec![1, 2]);
         sets.insert("C".to_string(), vec![2]);
 
-        let set_cover_0 = greedy_set_cover(&sets, "greedy-standard".to_string());
-        let set_cover_1 = greedy_set_cover(&sets, "greedy-bitvec".to_string());
-        let set_cover_2 = greedy_set_cover(&sets, "greedy-textbook".to_string());
+        let (set_cover_0, _cost_0) = greedy_set_cover(&sets, "greedy-standard".to_string(), None);
+        let (set_cover_1, _cost_1) = greedy_set_cover(&sets, "greedy-bitvec".to_string(), None);
+        let (set_cover_2, _cost_2) = greedy_set_cover(&sets, "greedy-textbook".to_string(), None);
         let universe = make_universe(&sets);
 
         fn check_coverage(
@@ -217,9 +380,9 @@ mod tests {
         sets.insert(2, vec![]);
         sets.insert(3, vec![3, 4, 5]);
 
-        let set_cover_0 = greedy_set_cover(&sets, "greedy-standard".to_string());
-        let set_cover_1 = greedy_set_cover(&sets, "greedy-bitvec".to_string());
-        let set_cover_2 = greedy_set_cover(&sets, "greedy-textbook".to_string());
+        let (set_cover_0, _cost_0) = greedy_set_cover(&sets, "greedy-standard".to_string(), None);
+        let (set_cover_1, _cost_1) = greedy_set_cover(&sets, "greedy-bitvec".to_string(), None);
+        let (set_cover_2, _cost_2) = greedy_set_cover(&sets, "greedy-textbook".to_string(), None);
         let universe = make_universe(&sets);
 
         fn check_coverage(cover: &[i32], sets: &HashMap<i32, Vec<i32>>, universe: &HashSet<i32>) {
@@ -247,9 +410,9 @@ mod tests {
         sets.insert(2, vec![2]);
         sets.insert(3, vec![3]);
 
-        let set_cover_0 = greedy_set_cover(&sets, "greedy-standard".to_string());
-        let set_cover_1 = greedy_set_cover(&sets, "greedy-bitvec".to_string());
-        let set_cover_2 = greedy_set_cover(&sets, "greedy-textbook".to_string());
+        let (set_cover_0, _cost_0) = greedy_set_cover(&sets, "greedy-standard".to_string(), None);
+        let (set_cover_1, _cost_1) = greedy_set_cover(&sets, "greedy-bitvec".to_string(), None);
+        let (set_cover_2, _cost_2) = greedy_set_cover(&sets, "greedy-textbook".to_string(), None);
 
         assert_eq!(sets.len(), set_cover_0.len());
         assert_eq!(sets.len(), set_cover_1.len());
@@ -278,9 +441,9 @@ mod tests {
         sets.insert(2, vec![1, 2]);
         sets.insert(3, vec![3, 4]);
 
-        let set_cover_0 = greedy_set_cover(&sets, "greedy-standard".to_string());
-        let set_cover_1 = greedy_set_cover(&sets, "greedy-bitvec".to_string());
-        let set_cover_2 = greedy_set_cover(&sets, "greedy-textbook".to_string());
+        let (set_cover_0, _cost_0) = greedy_set_cover(&sets, "greedy-standard".to_string(), None);
+        let (set_cover_1, _cost_1) = greedy_set_cover(&sets, "greedy-bitvec".to_string(), None);
+        let (set_cover_2, _cost_2) = greedy_set_cover(&sets, "greedy-textbook".to_string(), None);
 
         assert_eq!(set_cover_0.len(), 1);
         assert_eq!(set_cover_1.len(), 1);
@@ -312,9 +475,9 @@ mod tests {
         sets.insert(2, vec![3, 4, 5]);
         sets.insert(3, vec![5, 6, 7]);
 
-        let set_cover_0 = greedy_set_cover(&sets, "greedy-standard".to_string());
-        let set_cover_1 = greedy_set_cover(&sets, "greedy-bitvec".to_string());
-        let set_cover_2 = greedy_set_cover(&sets, "greedy-textbook".to_string());
+        let (set_cover_0, _cost_0) = greedy_set_cover(&sets, "greedy-standard".to_string(), None);
+        let (set_cover_1, _cost_1) = greedy_set_cover(&sets, "greedy-bitvec".to_string(), None);
+        let (set_cover_2, _cost_2) = greedy_set_cover(&sets, "greedy-textbook".to_string(), None);
 
         assert_eq!(set_cover_0.len(), 3);
         assert_eq!(set_cover_1.len(), 3);
@@ -345,9 +508,9 @@ mod tests {
         sets.insert(4, vec![5, 6, 9]);
         sets.insert(5, vec![7, 8, 9, 10]); // S5 (Best second choice to cover 7,8,9,10)
 
-        let set_cover_0 = greedy_set_cover(&sets, "greedy-standard".to_string());
-        let set_cover_1 = greedy_set_cover(&sets, "greedy-bitvec".to_string());
-        let set_cover_2 = greedy_set_cover(&sets, "greedy-textbook".to_string());
+        let (set_cover_0, _cost_0) = greedy_set_cover(&sets, "greedy-standard".to_string(), None);
+        let (set_cover_1, _cost_1) = greedy_set_cover(&sets, "greedy-bitvec".to_string(), None);
+        let (set_cover_2, _cost_2) = greedy_set_cover(&sets, "greedy-textbook".to_string(), None);
 
         assert_eq!(set_cover_0, vec![1, 5]);
         assert_eq!(set_cover_1, vec![1, 5]);
@@ -376,9 +539,9 @@ mod tests {
         sets.insert(2, vec![7, 8, 9]);
         sets.insert(4, vec![10, 11, 12]);
 
-        let set_cover_0 = greedy_set_cover(&sets, "greedy-standard".to_string());
-        let set_cover_1 = greedy_set_cover(&sets, "greedy-bitvec".to_string());
-        let set_cover_2 = greedy_set_cover(&sets, "greedy-textbook".to_string());
+        let (set_cover_0, _cost_0) = greedy_set_cover(&sets, "greedy-standard".to_string(), None);
+        let (set_cover_1, _cost_1) = greedy_set_cover(&sets, "greedy-bitvec".to_string(), None);
+        let (set_cover_2, _cost_2) = greedy_set_cover(&sets, "greedy-textbook".to_string(), None);
 
         let expected = vec![1, 2, 3, 4];
         assert_eq!(set_cover_0, expected);
@@ -405,12 +568,193 @@ mod tests {
         sets.insert(1, vec![1]);
         sets.insert(2, vec![2]);
 
-        let set_cover_0 = greedy_set_cover(&sets, "greedy-standard".to_string());
-        let set_cover_1 = greedy_set_cover(&sets, "greedy-bitvec".to_string());
-        let set_cover_2 = greedy_set_cover(&sets, "greedy-textbook".to_string());
+        let (set_cover_0, _cost_0) = greedy_set_cover(&sets, "greedy-standard".to_string(), None);
+        let (set_cover_1, _cost_1) = greedy_set_cover(&sets, "greedy-bitvec".to_string(), None);
+        let (set_cover_2, _cost_2) = greedy_set_cover(&sets, "greedy-textbook".to_string(), None);
 
         assert_eq!(set_cover_0.len(), 2);
         assert_eq!(set_cover_1.len(), 2);
         assert_eq!(set_cover_2.len(), 2);
     }
+
+    #[test]
+    fn test_max_cover_respects_budget() {
+        let mut sets = HashMap::new();
+        sets.insert(1, vec![1, 2, 3, 4, 5, 6]);
+        sets.insert(2, vec![1, 2, 7]);
+        sets.insert(3, vec![3, 4, 8]);
+        sets.insert(4, vec![5, 6, 9]);
+        sets.insert(5, vec![7, 8, 9, 10]);
+
+        let (cover_0, covered_0) = greedy_max_cover(&sets, "greedy-standard".to_string(), 2);
+        let (cover_1, covered_1) = greedy_max_cover(&sets, "greedy-bitvec".to_string(), 2);
+
+        assert_eq!(cover_0, vec![1, 5]);
+        assert_eq!(cover_1, vec![1, 5]);
+        assert_eq!(covered_0, 10);
+        assert_eq!(covered_1, 10);
+    }
+
+    #[test]
+    fn test_max_cover_stops_when_budget_exceeds_need() {
+        let mut sets = HashMap::new();
+        sets.insert(1, vec![1, 2, 3]);
+        sets.insert(2, vec![1, 2]);
+        sets.insert(3, vec![2]);
+
+        let (cover_0, covered_0) = greedy_max_cover(&sets, "greedy-standard".to_string(), 5);
+        let (cover_1, covered_1) = greedy_max_cover(&sets, "greedy-bitvec".to_string(), 5);
+
+        assert_eq!(cover_0, vec![1]);
+        assert_eq!(cover_1, vec![1]);
+        assert_eq!(covered_0, 3);
+        assert_eq!(covered_1, 3);
+    }
+
+    #[test]
+    fn test_weighted_cover_prefers_cheap_set_over_bigger_one() {
+        let mut sets = HashMap::new();
+        sets.insert("big".to_string(), vec![1, 2, 3, 4]);
+        sets.insert("cheap".to_string(), vec![1, 2, 3]);
+        sets.insert("rest".to_string(), vec![4]);
+
+        let mut costs = HashMap::new();
+        costs.insert("big".to_string(), 100.0);
+        costs.insert("cheap".to_string(), 1.0);
+        costs.insert("rest".to_string(), 1.0);
+
+        let (cover, total_cost) =
+            greedy_set_cover(&sets, "greedy-standard".to_string(), Some(&costs));
+
+        assert_eq!(cover, vec!["cheap".to_string(), "rest".to_string()]);
+        assert_eq!(total_cost, 2.0);
+    }
+
+    #[test]
+    fn test_weighted_cover_matches_unweighted_with_default_costs() {
+        let mut sets = HashMap::new();
+        sets.insert(1, vec![1, 2, 3]);
+        sets.insert(2, vec![1, 2]);
+        sets.insert(3, vec![2]);
+
+        let (cover_dense, cost_dense) = greedy_set_cover(&sets, "greedy-standard".to_string(), None);
+        let (cover_bitset, cost_bitset) = greedy_set_cover(&sets, "greedy-bitvec".to_string(), None);
+        let (cover_textbook, cost_textbook) =
+            greedy_set_cover(&sets, "greedy-textbook".to_string(), None);
+
+        assert_eq!(cover_dense, vec![1]);
+        assert_eq!(cover_bitset, vec![1]);
+        assert_eq!(cover_textbook, vec![1]);
+        assert_eq!(cost_dense, 1.0);
+        assert_eq!(cost_bitset, 1.0);
+        assert_eq!(cost_textbook, 1.0);
+    }
+
+    #[test]
+    fn test_lazy_greedy_matches_plain_greedy() {
+        let mut sets = HashMap::new();
+        sets.insert(1, vec![1, 2, 3, 4, 5, 6]); // S1 (Best initial choice)
+        sets.insert(2, vec![1, 2, 7]);
+        sets.insert(3, vec![3, 4, 8]);
+        sets.insert(4, vec![5, 6, 9]);
+        sets.insert(5, vec![7, 8, 9, 10]); // S5 (Best second choice to cover 7,8,9,10)
+
+        let (set_cover_lazy, cost_lazy) = greedy_set_cover(&sets, "greedy-lazy".to_string(), None);
+
+        assert_eq!(set_cover_lazy, vec![1, 5]);
+        assert_eq!(cost_lazy, 2.0);
+
+        let universe = make_universe(&sets);
+        let covered_sets: HashMap<i32, Vec<i32>> = set_cover_lazy
+            .iter()
+            .map(|&key| (key, sets.get(&key).unwrap().clone()))
+            .collect();
+        assert_eq!(universe, make_universe(&covered_sets));
+    }
+
+    #[test]
+    fn test_lazy_greedy_with_empty_set() {
+        let mut sets = HashMap::new();
+        sets.insert(1, vec![1, 2, 3]);
+        sets.insert(2, vec![]);
+        sets.insert(3, vec![3, 4, 5]);
+
+        let (set_cover_lazy, _cost_lazy) = greedy_set_cover(&sets, "greedy-lazy".to_string(), None);
+
+        assert_eq!(set_cover_lazy, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_lazy_greedy_prefers_cheap_set_over_bigger_one() {
+        let mut sets = HashMap::new();
+        sets.insert("big".to_string(), vec![1, 2, 3, 4]);
+        sets.insert("cheap".to_string(), vec![1, 2, 3]);
+        sets.insert("rest".to_string(), vec![4]);
+
+        let mut costs = HashMap::new();
+        costs.insert("big".to_string(), 100.0);
+        costs.insert("cheap".to_string(), 1.0);
+        costs.insert("rest".to_string(), 1.0);
+
+        let (cover, total_cost) = greedy_set_cover(&sets, "greedy-lazy".to_string(), Some(&costs));
+
+        assert_eq!(cover, vec!["cheap".to_string(), "rest".to_string()]);
+        assert_eq!(total_cost, 2.0);
+    }
+
+    #[test]
+    fn test_hybrid_bitset_matches_dense_bitset() {
+        let universe_size = 10;
+        let sets = [
+            vec![0usize, 1, 2, 3, 4, 5],
+            vec![0, 1, 6],
+            vec![2, 3, 7],
+            vec![4, 5, 8],
+            vec![6, 7, 8, 9],
+        ];
+        let costs = vec![1.0; sets.len()];
+
+        let dense_bits: Vec<BitSet> = sets
+            .iter()
+            .map(|s| bitset::make_bitset(universe_size, s))
+            .collect();
+        let hybrid_bits: Vec<HybridBitSet> = sets
+            .iter()
+            .map(|s| bitset::make_hybrid_bitset(universe_size, s))
+            .collect();
+
+        let dense_result = greedy_set_cover_bitset(universe_size, &dense_bits, &costs);
+        let hybrid_result = greedy_set_cover_bitset_hybrid(universe_size, &hybrid_bits, &costs);
+
+        assert_eq!(dense_result, hybrid_result);
+        assert_eq!(dense_result, Some((vec![0, 4], 2.0)));
+    }
+
+    #[test]
+    fn test_maximum_cover_matches_greedy_max_cover() {
+        let items = vec![
+            CoverSet::new(1, &[1, 2, 3, 4, 5, 6]), // S1 (Best initial choice)
+            CoverSet::new(2, &[1, 2, 7]),
+            CoverSet::new(3, &[3, 4, 8]),
+            CoverSet::new(4, &[5, 6, 9]),
+            CoverSet::new(5, &[7, 8, 9, 10]), // S5 (Best second choice)
+        ];
+
+        let chosen = maximum_cover(items, 2);
+
+        assert_eq!(chosen, vec![1, 5]);
+    }
+
+    #[test]
+    fn test_maximum_cover_stops_when_scores_drop_to_zero() {
+        let items = vec![
+            CoverSet::new("A", &[1, 2, 3]),
+            CoverSet::new("B", &[1, 2]),
+            CoverSet::new("C", &[2]),
+        ];
+
+        let chosen = maximum_cover(items, 5);
+
+        assert_eq!(chosen, vec!["A"]);
+    }
 }
@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// An item that can participate in the greedy maximum-coverage loop
+/// without first being flattened into `Vec<Vec<T>>`.
+///
+/// This generalizes the crate beyond `HashMap<K, Vec<T>>` sets: an item
+/// can carry its own weight, payload, or a lazily-computed covering set,
+/// as long as it can report a score and update itself once another item
+/// has been chosen.
+pub trait MaxCover {
+    /// The remaining covering set of elements for this item.
+    type Set;
+    /// The value recorded into the solution when this item is selected.
+    type Object;
+
+    /// The item's remaining covering set.
+    fn covering_set(&self) -> &Self::Set;
+
+    /// Quality of the remaining covering set. Typically its cardinality,
+    /// but implementors are free to weigh it differently.
+    fn score(&self) -> usize;
+
+    /// The value emitted into the solution when this item is chosen.
+    fn object(&self) -> Self::Object;
+
+    /// Remove the elements covered by `chosen_set` (the just-selected
+    /// item's covering set) from `self`'s remaining covering set.
+    fn update_covering_set(&mut self, chosen_obj: &Self::Object, chosen_set: &Self::Set);
+}
+
+/// Greedy maximum coverage over arbitrary [`MaxCover`] items.
+///
+/// Each of up to `limit` rounds scans the available items for the one
+/// with the highest `score()`, records its `object()`, and calls
+/// `update_covering_set` on every other item so later rounds see the
+/// reduced universe. Consumed items are marked unavailable rather than
+/// removed, matching `update_covering_set`'s `&mut self` borrow. Stops
+/// early once no available item has positive score.
+pub fn maximum_cover<I, M>(items: I, limit: usize) -> Vec<M::Object>
+where
+    I: IntoIterator<Item = M>,
+    M: MaxCover,
+{
+    let mut pool: Vec<M> = items.into_iter().collect();
+    let mut available = vec![true; pool.len()];
+    let mut result = Vec::new();
+
+    for _ in 0..limit {
+        let mut best_idx: Option<usize> = None;
+        let mut best_score = 0usize;
+
+        for (idx, item) in pool.iter().enumerate() {
+            if !available[idx] {
+                continue;
+            }
+            let score = item.score();
+            if score > best_score {
+                best_score = score;
+                best_idx = Some(idx);
+            }
+        }
+
+        let idx = match best_idx {
+            Some(i) if best_score > 0 => i,
+            _ => break,
+        };
+
+        available[idx] = false;
+
+        let (before, after) = pool.split_at_mut(idx);
+        let (chosen, after_rest) = after.split_first_mut().expect("idx is in bounds");
+        let chosen_obj = chosen.object();
+        let chosen_set = chosen.covering_set();
+        for other in before.iter_mut().chain(after_rest.iter_mut()) {
+            other.update_covering_set(&chosen_obj, chosen_set);
+        }
+
+        result.push(chosen_obj);
+    }
+
+    result
+}
+
+/// [`MaxCover`] adapter over the crate's historical keyed `Vec<T>` sets,
+/// so `maximum_cover` can reproduce the same greedy selection as
+/// `greedy_max_cover_generic` for hashable elements.
+pub struct CoverSet<K, T> {
+    key: K,
+    elements: HashSet<T>,
+}
+
+impl<K, T: Eq + Hash + Clone> CoverSet<K, T> {
+    /// Build a `CoverSet` from a key and its set of elements.
+    pub fn new(key: K, elements: &[T]) -> Self {
+        CoverSet {
+            key,
+            elements: elements.iter().cloned().collect(),
+        }
+    }
+}
+
+impl<K: Clone, T: Eq + Hash> MaxCover for CoverSet<K, T> {
+    type Set = HashSet<T>;
+    type Object = K;
+
+    fn covering_set(&self) -> &Self::Set {
+        &self.elements
+    }
+
+    fn score(&self) -> usize {
+        self.elements.len()
+    }
+
+    fn object(&self) -> Self::Object {
+        self.key.clone()
+    }
+
+    fn update_covering_set(&mut self, _chosen_obj: &Self::Object, chosen_set: &Self::Set) {
+        self.elements.retain(|e| !chosen_set.contains(e));
+    }
+}
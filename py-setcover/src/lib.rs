@@ -2,40 +2,88 @@ use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use std::collections::HashMap;
 
-use setcover_core::{greedy_set_cover, greedy_set_cover_dense};
+use setcover_core::{greedy_max_cover, greedy_set_cover, greedy_set_cover_dense};
 
 #[pyfunction]
 fn greedy_set_cover_string_i64(
     sets: HashMap<String, Vec<i64>>,
     algo: String,
-) -> PyResult<Vec<String>> {
-    Ok(greedy_set_cover(&sets, algo))
+    costs: Option<HashMap<String, f64>>,
+) -> PyResult<(Vec<String>, f64)> {
+    Ok(greedy_set_cover(&sets, algo, costs.as_ref()))
 }
 
 #[pyfunction]
 fn greedy_set_cover_string_string(
     sets: HashMap<String, Vec<String>>,
     algo: String,
-) -> PyResult<Vec<String>> {
-    Ok(greedy_set_cover(&sets, algo))
+    costs: Option<HashMap<String, f64>>,
+) -> PyResult<(Vec<String>, f64)> {
+    Ok(greedy_set_cover(&sets, algo, costs.as_ref()))
 }
 
 #[pyfunction]
-fn greedy_set_cover_i64_i64(sets: HashMap<i64, Vec<i64>>, algo: String) -> PyResult<Vec<i64>> {
-    Ok(greedy_set_cover(&sets, algo))
+fn greedy_set_cover_i64_i64(
+    sets: HashMap<i64, Vec<i64>>,
+    algo: String,
+    costs: Option<HashMap<i64, f64>>,
+) -> PyResult<(Vec<i64>, f64)> {
+    Ok(greedy_set_cover(&sets, algo, costs.as_ref()))
 }
 
 #[pyfunction]
 fn greedy_set_cover_i64_string(
     sets: HashMap<i64, Vec<String>>,
     algo: String,
-) -> PyResult<Vec<i64>> {
-    Ok(greedy_set_cover(&sets, algo))
+    costs: Option<HashMap<i64, f64>>,
+) -> PyResult<(Vec<i64>, f64)> {
+    Ok(greedy_set_cover(&sets, algo, costs.as_ref()))
+}
+
+#[pyfunction]
+fn greedy_max_cover_string_i64(
+    sets: HashMap<String, Vec<i64>>,
+    algo: String,
+    k: usize,
+) -> PyResult<(Vec<String>, usize)> {
+    Ok(greedy_max_cover(&sets, algo, k))
+}
+
+#[pyfunction]
+fn greedy_max_cover_string_string(
+    sets: HashMap<String, Vec<String>>,
+    algo: String,
+    k: usize,
+) -> PyResult<(Vec<String>, usize)> {
+    Ok(greedy_max_cover(&sets, algo, k))
+}
+
+#[pyfunction]
+fn greedy_max_cover_i64_i64(
+    sets: HashMap<i64, Vec<i64>>,
+    algo: String,
+    k: usize,
+) -> PyResult<(Vec<i64>, usize)> {
+    Ok(greedy_max_cover(&sets, algo, k))
+}
+
+#[pyfunction]
+fn greedy_max_cover_i64_string(
+    sets: HashMap<i64, Vec<String>>,
+    algo: String,
+    k: usize,
+) -> PyResult<(Vec<i64>, usize)> {
+    Ok(greedy_max_cover(&sets, algo, k))
 }
 
 #[pyfunction]
-fn greedy_set_cover_dense_py(universe_size: usize, sets: Vec<Vec<usize>>) -> PyResult<Vec<usize>> {
-    greedy_set_cover_dense(universe_size, &sets).ok_or_else(|| {
+fn greedy_set_cover_dense_py(
+    universe_size: usize,
+    sets: Vec<Vec<usize>>,
+    costs: Option<Vec<f64>>,
+) -> PyResult<(Vec<usize>, f64)> {
+    let costs = costs.unwrap_or_else(|| vec![1.0; sets.len()]);
+    greedy_set_cover_dense(universe_size, &sets, &costs).ok_or_else(|| {
         PyValueError::new_err("Unable to find a set cover for the provided dataset.")
     })
 }
@@ -47,6 +95,10 @@ fn _setcover_lib(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(greedy_set_cover_string_string, m)?)?;
     m.add_function(wrap_pyfunction!(greedy_set_cover_i64_i64, m)?)?;
     m.add_function(wrap_pyfunction!(greedy_set_cover_i64_string, m)?)?;
+    m.add_function(wrap_pyfunction!(greedy_max_cover_string_i64, m)?)?;
+    m.add_function(wrap_pyfunction!(greedy_max_cover_string_string, m)?)?;
+    m.add_function(wrap_pyfunction!(greedy_max_cover_i64_i64, m)?)?;
+    m.add_function(wrap_pyfunction!(greedy_max_cover_i64_string, m)?)?;
     m.add_function(wrap_pyfunction!(greedy_set_cover_dense_py, m)?)?;
     Ok(())
 }